@@ -0,0 +1,81 @@
+//! `cargo audit` configuration
+
+use serde::{Deserialize, Serialize};
+
+/// Vulnerability information presenter configuration
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputConfig {
+    /// Output format to use
+    #[serde(default)]
+    pub format: OutputFormat,
+
+    /// Whether or not to display the inverse dependency tree for each vulnerability
+    pub show_tree: Option<bool>,
+
+    /// Whether or not to suppress output entirely
+    pub quiet: Option<bool>,
+
+    /// Minimum severity to report. Vulnerabilities below this threshold are
+    /// omitted from the terminal report and from the fix plan; the JSON report
+    /// and the SARIF log still list every vulnerability.
+    pub min_severity: Option<Severity>,
+
+    /// Render a trimmed dependency tree: rather than the full inverse subgraph,
+    /// show only the shortest path from the vulnerable crate to each reachable
+    /// workspace/root package, with the direct dependency to upgrade highlighted.
+    pub trim_tree: Option<bool>,
+
+    /// Compute and render a consolidated fix plan: the minimum version each
+    /// vulnerable crate needs to be bumped to in order to clear every advisory
+    /// against it. Shown as a "Recommended upgrades" table in terminal mode,
+    /// and as a `fixes` array alongside the report in JSON mode.
+    pub fix_plan: Option<bool>,
+}
+
+impl OutputConfig {
+    /// Should output be suppressed?
+    pub fn is_quiet(&self) -> bool {
+        self.format == OutputFormat::Json
+            || self.format == OutputFormat::Sarif
+            || self.quiet.unwrap_or(false)
+    }
+}
+
+/// Format to output vulnerability information in
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Display the vulnerability report in a human-readable format for terminals
+    Terminal,
+
+    /// Display the vulnerability report as JSON
+    Json,
+
+    /// Display the vulnerability report as a SARIF 2.1.0 log, for consumption by
+    /// code-scanning tools (GitHub code scanning, GitLab, etc.)
+    Sarif,
+}
+
+impl Default for OutputFormat {
+    fn default() -> OutputFormat {
+        OutputFormat::Terminal
+    }
+}
+
+/// Severity bucket a vulnerability is sorted into, derived from its CVSS base score
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// No CVSS score, or a score in the "low" range
+    Low,
+
+    /// A CVSS score in the "medium" range
+    Medium,
+
+    /// A CVSS score in the "high" range
+    High,
+
+    /// A CVSS score in the "critical" range
+    Critical,
+}
@@ -1,31 +1,116 @@
 //! Presenter for `rustsec::Report` information.
 
 use crate::{
-    config::{OutputConfig, OutputFormat},
+    config::{OutputConfig, OutputFormat, Severity},
     prelude::*,
 };
 use abscissa_core::terminal::{
     self,
-    Color::{self, Red, Yellow},
+    Color::{self, Red, White, Yellow},
 };
 use rustsec::{
     cargo_lock::{
-        dependency::{self, graph::EdgeDirection, Dependency},
+        dependency::{
+            self,
+            graph::{EdgeDirection, NodeIndex},
+            Dependency,
+        },
         Lockfile, Package,
     },
+    warning::Kind,
     Vulnerability, Warning,
 };
-use std::{collections::BTreeSet as Set, io, path::Path};
+use serde::Serialize;
+use std::{
+    collections::{BTreeSet as Set, HashMap, VecDeque},
+    io,
+    path::{Path, PathBuf},
+};
 
 /// Vulnerability information presenter
 #[derive(Clone, Debug)]
 pub struct Presenter {
     /// Track packages we've displayed once so we don't show the same dep tree
-    // TODO(tarcieri): group advisories about the same package?
     displayed_packages: Set<Dependency>,
 
     /// Output configuration
     config: OutputConfig,
+
+    /// Path to the `Cargo.lock` file being scanned
+    lockfile_path: Option<PathBuf>,
+}
+
+/// A single recommended upgrade for a vulnerable package, consolidated across
+/// every advisory that affects it
+#[derive(Clone, Debug, Serialize)]
+struct FixPlanEntry {
+    /// Name of the vulnerable crate
+    #[serde(rename = "crate")]
+    krate: String,
+
+    /// Version currently locked in `Cargo.lock`
+    from: String,
+
+    /// Minimum version that clears every advisory against this crate, or (when
+    /// the advisories don't share a common patched floor) each one's accepted
+    /// version range joined by " OR "
+    to: String,
+
+    /// IDs of the advisories this upgrade fixes
+    fixes: Vec<String>,
+}
+
+/// Find the single version that satisfies every one of `reqs`, if one exists.
+///
+/// Each requirement's lower bound is taken as a candidate (we only handle the
+/// common RustSec case of a simple `>=`/`>` floor per advisory); the highest
+/// candidate is then validated against every requirement via
+/// [`semver::VersionReq::matches`] so a requirement with an upper bound (e.g.
+/// `>=1.2.3, <2.0.0`) can't be satisfied by a higher floor from another advisory.
+fn intersect_patched_versions(reqs: &[semver::VersionReq]) -> Option<semver::Version> {
+    let candidates: Vec<semver::Version> = reqs
+        .iter()
+        .filter_map(|req| {
+            req.comparators
+                .iter()
+                .filter(|comparator| {
+                    matches!(comparator.op, semver::Op::GreaterEq | semver::Op::Greater)
+                })
+                .map(|comparator| {
+                    semver::Version::new(
+                        comparator.major,
+                        comparator.minor.unwrap_or(0),
+                        comparator.patch.unwrap_or(0),
+                    )
+                })
+                .max()
+        })
+        .collect();
+
+    if candidates.len() != reqs.len() {
+        return None;
+    }
+
+    let candidate = candidates.into_iter().max()?;
+
+    reqs.iter()
+        .all(|req| req.matches(&candidate))
+        .then_some(candidate)
+}
+
+/// Short label for a warning's kind, used in SARIF rule/message text for
+/// warnings without an advisory.
+///
+/// `Kind` is marked non-exhaustive upstream, so unknown future variants fall
+/// back to "other" rather than failing to compile.
+fn warning_kind_label(kind: &Kind) -> &'static str {
+    match kind {
+        Kind::Unmaintained => "unmaintained",
+        Kind::Unsound => "unsound",
+        Kind::Yanked => "yanked",
+        Kind::Notice => "informational",
+        _ => "other",
+    }
 }
 
 impl Presenter {
@@ -34,11 +119,14 @@ impl Presenter {
         Self {
             displayed_packages: Set::new(),
             config: config.clone(),
+            lockfile_path: None,
         }
     }
 
     /// Information to display before a report is generated
     pub fn before_report(&mut self, lockfile_path: &Path, lockfile: &Lockfile) {
+        self.lockfile_path = Some(lockfile_path.to_owned());
+
         if !self.config.is_quiet() {
             status_ok!(
                 "Scanning",
@@ -52,102 +140,409 @@ impl Presenter {
     /// Print the vulnerability report generated by an audit
     pub fn print_report(&mut self, report: &rustsec::Report, lockfile: &Lockfile) {
         if self.config.format == OutputFormat::Json {
-            serde_json::to_writer(io::stdout(), &report).unwrap();
+            // Opt-in here (unlike the terminal table) so existing JSON consumers
+            // parsing the raw `rustsec::Report` aren't handed a changed schema
+            // unless they ask for it.
+            if self.config.fix_plan.unwrap_or(false) {
+                let output = serde_json::json!({
+                    "report": report,
+                    "fixes": self.compute_fix_plan(report),
+                });
+                serde_json::to_writer(io::stdout(), &output).unwrap();
+            } else {
+                serde_json::to_writer(io::stdout(), &report).unwrap();
+            }
             return;
         }
 
-        if report.vulnerabilities.found {
-            status_err!("Vulnerable crates found!");
-        } else {
-            status_ok!("Success", "No vulnerable packages found");
+        if self.config.format == OutputFormat::Sarif {
+            serde_json::to_writer(io::stdout(), &self.sarif_log(report)).unwrap();
+            return;
         }
 
         let tree = lockfile
             .dependency_tree()
             .expect("invalid Cargo.lock dependency tree");
 
-        for vulnerability in &report.vulnerabilities.list {
-            self.print_vulnerability(vulnerability, &tree);
+        let mut groups = self.filtered_groups(report);
+
+        groups.sort_by_key(|(_, vulnerabilities)| {
+            vulnerabilities
+                .iter()
+                .map(|vulnerability| self.severity(&vulnerability.advisory).0)
+                .max()
+                .unwrap()
+        });
+
+        // Derived from the filtered groups (not `report.vulnerabilities`) so both
+        // banners honor `min_severity` the same way the list body does -- otherwise
+        // an all-filtered-out report prints "Vulnerable crates found!" over an
+        // empty body.
+        let found = groups
+            .iter()
+            .map(|(_, vulnerabilities)| vulnerabilities.len())
+            .sum::<usize>();
+
+        if found > 0 {
+            status_err!("Vulnerable crates found!");
+        } else {
+            status_ok!("Success", "No vulnerable packages found");
+        }
+
+        for (_, vulnerabilities) in groups {
+            self.print_vulnerability_group(&vulnerabilities, &tree);
         }
 
         if !report.warnings.is_empty() {
             println!();
             status_warn!("found informational advisories for dependencies");
 
-            for warning in &report.warnings {
-                self.print_warning(warning, &tree)
-            }
+            self.print_warnings(&report.warnings, &tree);
+        }
+
+        if found > 0 && self.config.fix_plan.unwrap_or(true) {
+            self.print_fix_plan(report, &tree);
         }
 
-        if report.vulnerabilities.found {
+        if found > 0 {
             println!();
 
-            if report.vulnerabilities.count == 1 {
+            if found == 1 {
                 status_err!("1 vulnerability found!");
             } else {
-                status_err!("{} vulnerabilities found!", report.vulnerabilities.count);
+                status_err!("{} vulnerabilities found!", found);
             }
         }
     }
 
-    /// Print information about the given vulnerability
-    fn print_vulnerability(&mut self, vulnerability: &Vulnerability, tree: &dependency::Tree) {
-        let advisory = &vulnerability.advisory;
+    /// Print information about every advisory affecting a single package as one block,
+    /// sharing a single header and dependency tree rather than repeating them per advisory.
+    fn print_vulnerability_group(
+        &mut self,
+        vulnerabilities: &[&Vulnerability],
+        tree: &dependency::Tree,
+    ) {
+        let package = &vulnerabilities[0].package;
+        let worst = vulnerabilities
+            .iter()
+            .map(|vulnerability| self.severity(&vulnerability.advisory).0)
+            .max()
+            .unwrap();
+        let color = self.severity_color(worst);
+        let bold = self.severity_bold(worst);
 
         println!();
-        self.print_attr(Red, "ID:      ", &advisory.id);
-        self.print_attr(Red, "Crate:   ", &vulnerability.package.name);
-        self.print_attr(Red, "Version: ", &vulnerability.package.version.to_string());
-        self.print_attr(Red, "Date:    ", &advisory.date);
+        self.print_attr_styled(color, bold, "Crate:   ", &package.name);
+        self.print_attr_styled(color, bold, "Version: ", &package.version.to_string());
+
+        for vulnerability in vulnerabilities {
+            let advisory = &vulnerability.advisory;
+            let (severity, score) = self.severity(advisory);
+            let color = self.severity_color(severity);
+            let bold = self.severity_bold(severity);
+
+            println!();
+            self.print_attr_styled(color, bold, "  ID:      ", &advisory.id);
+            self.print_attr_styled(color, bold, "  Date:    ", &advisory.date);
 
-        if let Some(url) = advisory.id.url() {
-            self.print_attr(Red, "URL:     ", &url);
-        } else if let Some(url) = &advisory.url {
-            self.print_attr(Red, "URL:     ", url);
+            if let Some(url) = advisory.id.url() {
+                self.print_attr_styled(color, bold, "  URL:     ", &url);
+            } else if let Some(url) = &advisory.url {
+                self.print_attr_styled(color, bold, "  URL:     ", url);
+            }
+
+            self.print_attr_styled(color, bold, "  Title:   ", &advisory.title);
+            self.print_attr_styled(
+                color,
+                bold,
+                "  Severity:",
+                match score {
+                    Some(score) => format!("{:?} ({:.1})", severity, score),
+                    None => format!("{:?}", severity),
+                },
+            );
         }
 
-        self.print_attr(Red, "Title:   ", &advisory.title);
-        self.print_attr(
-            Red,
+        println!();
+        self.print_attr_styled(
+            color,
+            bold,
             "Solution: upgrade to",
-            &vulnerability
-                .versions
-                .patched
+            self.combined_solution(vulnerabilities),
+        );
+
+        self.print_tree(color, package, tree);
+    }
+
+    /// Intersect the patched version ranges of every advisory affecting a package,
+    /// returning the single version that clears all of them when that's unambiguous,
+    /// falling back to listing each advisory's accepted ranges otherwise.
+    fn combined_solution(&self, vulnerabilities: &[&Vulnerability]) -> String {
+        let reqs: Option<Vec<semver::VersionReq>> = vulnerabilities
+            .iter()
+            .map(
+                |vulnerability| match vulnerability.versions.patched.as_slice() {
+                    [req] => Some(req.clone()),
+                    _ => None,
+                },
+            )
+            .collect();
+
+        let intersected = reqs.as_deref().and_then(intersect_patched_versions);
+
+        match intersected {
+            Some(version) => version.to_string(),
+            None => vulnerabilities
                 .iter()
-                .map(ToString::to_string)
+                .flat_map(|vulnerability| {
+                    vulnerability
+                        .versions
+                        .patched
+                        .iter()
+                        .map(ToString::to_string)
+                })
                 .collect::<Vec<_>>()
-                .as_slice()
                 .join(" OR "),
+        }
+    }
+
+    /// Bucket every vulnerability in the report by the package it affects
+    fn group_vulnerabilities<'r>(
+        &self,
+        report: &'r rustsec::Report,
+    ) -> Vec<(Dependency, Vec<&'r Vulnerability>)> {
+        let mut groups: Vec<(Dependency, Vec<&Vulnerability>)> = vec![];
+
+        for vulnerability in &report.vulnerabilities.list {
+            let key = Dependency::from(vulnerability.package.clone());
+
+            match groups.iter_mut().find(|(pkg, _)| *pkg == key) {
+                Some((_, vulnerabilities)) => vulnerabilities.push(vulnerability),
+                None => groups.push((key, vec![vulnerability])),
+            }
+        }
+
+        groups
+    }
+
+    /// Like [`Self::group_vulnerabilities`], but drops vulnerabilities below
+    /// [`OutputConfig::min_severity`] (and any package left with none) so that the
+    /// terminal report, its banners and the fix plan agree on what's shown. The SARIF
+    /// log and the raw JSON report do not use it and list every vulnerability.
+    fn filtered_groups<'r>(
+        &self,
+        report: &'r rustsec::Report,
+    ) -> Vec<(Dependency, Vec<&'r Vulnerability>)> {
+        let min_severity = self.config.min_severity.unwrap_or(Severity::Low);
+        let mut groups = self.group_vulnerabilities(report);
+
+        for (_, vulnerabilities) in &mut groups {
+            vulnerabilities
+                .retain(|vulnerability| self.severity(&vulnerability.advisory).0 >= min_severity);
+        }
+
+        groups.retain(|(_, vulnerabilities)| !vulnerabilities.is_empty());
+        groups
+    }
+
+    /// Resolve the single recommended upgrade for every advisory affecting one package
+    fn fix_plan_entry(&self, vulnerabilities: &[&Vulnerability]) -> FixPlanEntry {
+        let package = &vulnerabilities[0].package;
+
+        FixPlanEntry {
+            krate: package.name.to_string(),
+            from: package.version.to_string(),
+            to: self.combined_solution(vulnerabilities),
+            fixes: vulnerabilities
+                .iter()
+                .map(|vulnerability| vulnerability.advisory.id.to_string())
+                .collect(),
+        }
+    }
+
+    /// Name of the direct dependency that must be bumped in `Cargo.lock` to pull in
+    /// a fixed version of `package`, found via the nearest reachable root package
+    fn direct_dependency(&self, package: &Package, tree: &dependency::Tree) -> String {
+        self.shortest_paths_to_roots(package, tree)
+            .first()
+            .map(|path| {
+                let node = path.get(1).copied().unwrap_or(path[0]);
+                tree.graph()[node].name.to_string()
+            })
+            .unwrap_or_else(|| package.name.to_string())
+    }
+
+    /// Compute the consolidated fix plan for every vulnerable package in the report
+    fn compute_fix_plan(&self, report: &rustsec::Report) -> Vec<FixPlanEntry> {
+        self.filtered_groups(report)
+            .into_iter()
+            .map(|(_, vulnerabilities)| self.fix_plan_entry(&vulnerabilities))
+            .collect()
+    }
+
+    /// Print a "Recommended upgrades" table summarizing the fix plan
+    fn print_fix_plan(&mut self, report: &rustsec::Report, tree: &dependency::Tree) {
+        let groups = self.filtered_groups(report);
+
+        if groups.is_empty() {
+            return;
+        }
+
+        println!();
+        status_ok!(
+            "Recommended upgrades",
+            "{} crate(s) to update",
+            groups.len()
         );
 
-        self.print_tree(Red, &vulnerability.package, tree);
+        for (_, vulnerabilities) in &groups {
+            let package = &vulnerabilities[0].package;
+            let entry = self.fix_plan_entry(vulnerabilities);
+            let direct_dependency = self.direct_dependency(package, tree);
+
+            println!(
+                "  {} {} -> {}  (via {}; fixes {})",
+                entry.krate,
+                entry.from,
+                entry.to,
+                direct_dependency,
+                entry.fixes.join(", "),
+            );
+        }
+    }
+
+    /// Print every warning grouped by kind (unmaintained/unsound/yanked/notice),
+    /// under a labeled section per kind, ending with a per-kind count summary.
+    ///
+    /// `Kind` is marked non-exhaustive upstream, so the match below carries a
+    /// catch-all "other" bucket -- without it, a future kind would silently
+    /// disappear from both the sections and the summary line while the "found
+    /// informational advisories" banner above still printed.
+    fn print_warnings(&mut self, warnings: &[Warning], tree: &dependency::Tree) {
+        let mut unmaintained = vec![];
+        let mut unsound = vec![];
+        let mut yanked = vec![];
+        let mut notice = vec![];
+        let mut other = vec![];
+
+        for warning in warnings {
+            match warning.kind {
+                Kind::Unmaintained => unmaintained.push(warning),
+                Kind::Unsound => unsound.push(warning),
+                Kind::Yanked => yanked.push(warning),
+                Kind::Notice => notice.push(warning),
+                _ => other.push(warning),
+            }
+        }
+
+        let groups: [(&str, Color, &[&Warning]); 5] = [
+            ("unmaintained", Red, &unmaintained),
+            ("unsound", Red, &unsound),
+            ("yanked", Yellow, &yanked),
+            ("informational", Yellow, &notice),
+            ("other", Yellow, &other),
+        ];
+
+        for (label, color, group) in groups {
+            if group.is_empty() {
+                continue;
+            }
+
+            println!();
+            self.print_attr(color, "Kind:    ", label);
+
+            for warning in group {
+                self.print_warning(color, warning, tree);
+            }
+        }
+
+        let summary = groups
+            .iter()
+            .filter(|(_, _, group)| !group.is_empty())
+            .map(|(label, _, group)| format!("{} {}", group.len(), label))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if !summary.is_empty() {
+            println!();
+            status_warn!("{}", summary);
+        }
     }
 
     /// Print information about a given warning
-    fn print_warning(&mut self, warning: &Warning, tree: &dependency::Tree) {
+    fn print_warning(&mut self, color: Color, warning: &Warning, tree: &dependency::Tree) {
         println!();
 
-        self.print_attr(Yellow, "Crate:   ", &warning.package.name);
-        self.print_attr(Red, "Title: ", &warning.advisory.title);
-        self.print_attr(Red, "Date:    ", &warning.advisory.date);
+        self.print_attr(color, "Crate:   ", &warning.package.name);
+
+        match &warning.advisory {
+            Some(advisory) => {
+                self.print_attr(color, "Title:   ", &advisory.title);
+                self.print_attr(color, "Date:    ", &advisory.date);
+
+                if let Some(url) = advisory.id.url() {
+                    self.print_attr(color, "URL:     ", &url);
+                } else if let Some(url) = &advisory.url {
+                    self.print_attr(color, "URL:     ", url);
+                }
+            }
+            // Yanked crates have no advisory: show yank/registry context instead
+            // of the blank Date/URL lines a security advisory would carry.
+            None => {
+                self.print_attr(color, "Version: ", &warning.package.version.to_string());
+
+                if let Some(source) = &warning.package.source {
+                    self.print_attr(color, "Registry:", &source.to_string());
+                }
+            }
+        }
+
+        self.print_tree(color, &warning.package, tree);
+    }
 
-        if let Some(url) = warning.advisory.id.url() {
-            self.print_attr(Yellow, "URL:     ", &url);
-        } else if let Some(url) = &warning.advisory.url {
-            self.print_attr(Yellow, "URL:     ", url);
+    /// Determine the severity bucket (and underlying CVSS score, if any) for an advisory
+    fn severity(&self, advisory: &rustsec::advisory::Metadata) -> (Severity, Option<f64>) {
+        match &advisory.cvss {
+            Some(cvss) => {
+                let score = cvss.score();
+                let bucket = match score.severity() {
+                    rustsec::cvss::Severity::Critical => Severity::Critical,
+                    rustsec::cvss::Severity::High => Severity::High,
+                    rustsec::cvss::Severity::Medium => Severity::Medium,
+                    rustsec::cvss::Severity::Low | rustsec::cvss::Severity::None => Severity::Low,
+                };
+                (bucket, Some(score.value()))
+            }
+            None => (Severity::Low, None),
         }
+    }
 
-        self.print_tree(Yellow, &warning.package, tree);
+    /// Choose the status color to print a vulnerability's attributes in, based on its severity
+    fn severity_color(&self, severity: Severity) -> Color {
+        match severity {
+            Severity::Critical | Severity::High => Red,
+            Severity::Medium => Yellow,
+            Severity::Low => White,
+        }
+    }
+
+    /// Whether a severity bucket should be printed bold. `Low` is printed plain so it
+    /// visually recedes next to the bold `Critical`/`High`/`Medium` entries.
+    fn severity_bold(&self, severity: Severity) -> bool {
+        severity != Severity::Low
     }
 
     /// Display an attribute of a particular vulnerability
     fn print_attr(&self, color: Color, attr: &str, content: impl AsRef<str>) {
-        terminal::status::Status::new()
-            .bold()
-            .color(color)
-            .status(attr)
-            .print_stdout(content.as_ref())
-            .unwrap();
+        self.print_attr_styled(color, true, attr, content);
+    }
+
+    /// Display an attribute, optionally without bolding it so it visually recedes
+    /// (used for `Severity::Low` entries, which have no bright/bold color of their own)
+    fn print_attr_styled(&self, color: Color, bold: bool, attr: &str, content: impl AsRef<str>) {
+        let status = terminal::status::Status::new().color(color).status(attr);
+        let status = if bold { status.bold() } else { status };
+        status.print_stdout(content.as_ref()).unwrap();
     }
 
     /// Print the inverse dependency tree to standard output
@@ -164,6 +559,15 @@ impl Presenter {
             return;
         }
 
+        if self.config.trim_tree.unwrap_or(false) {
+            self.print_trimmed_tree(color, package, tree);
+        } else {
+            self.print_full_tree(color, package, tree);
+        }
+    }
+
+    /// Print the full inverse dependency tree to standard output
+    fn print_full_tree(&self, color: Color, package: &Package, tree: &dependency::Tree) {
         terminal::status::Status::new()
             .bold()
             .color(color)
@@ -175,4 +579,425 @@ impl Presenter {
         tree.render(&mut io::stdout(), package_node, EdgeDirection::Incoming)
             .unwrap();
     }
+
+    /// Print only the shortest upgrade path from the vulnerable crate to each
+    /// reachable workspace/root package, highlighting the direct dependency
+    /// the user would need to bump.
+    fn print_trimmed_tree(&self, color: Color, package: &Package, tree: &dependency::Tree) {
+        let paths = self.shortest_paths_to_roots(package, tree);
+
+        if paths.is_empty() {
+            // No reachable root (e.g. the vulnerable crate is itself a root
+            // package) -- fall back to the exhaustive tree.
+            self.print_full_tree(color, package, tree);
+            return;
+        }
+
+        terminal::status::Status::new()
+            .bold()
+            .color(color)
+            .status("Upgrade paths:")
+            .print_stdout("")
+            .unwrap();
+
+        for path in paths {
+            for (depth, node) in path.iter().enumerate() {
+                let pkg = &tree.graph()[*node];
+                let marker = if depth == 1 { "  <-- upgrade this" } else { "" };
+                println!(
+                    "{}{} {}{}",
+                    "    ".repeat(depth),
+                    pkg.name,
+                    pkg.version,
+                    marker
+                );
+            }
+        }
+    }
+
+    /// BFS over the incoming-edge subgraph rooted at `package`, returning the
+    /// shortest path (as a list of nodes from root to `package`) to each
+    /// reachable root/workspace package. When a package is reachable from more
+    /// than one root, the returned paths are ordered by length (shortest
+    /// first), then lexicographically by root package name, so callers that
+    /// only want a single "primary" path get a deterministic choice rather
+    /// than whichever root the BFS happened to discover first.
+    fn shortest_paths_to_roots(
+        &self,
+        package: &Package,
+        tree: &dependency::Tree,
+    ) -> Vec<Vec<NodeIndex>> {
+        let graph = tree.graph();
+        let roots: Set<NodeIndex> = tree.roots().into_iter().collect();
+        let start = tree.nodes()[&Dependency::from(package.clone())];
+
+        let mut predecessor: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut visited: Set<NodeIndex> = Set::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(start);
+        queue.push_back(start);
+
+        let mut reached_roots = vec![];
+
+        while let Some(node) = queue.pop_front() {
+            if roots.contains(&node) && node != start {
+                reached_roots.push(node);
+            }
+
+            for ancestor in graph.neighbors_directed(node, EdgeDirection::Incoming) {
+                if visited.insert(ancestor) {
+                    predecessor.insert(ancestor, node);
+                    queue.push_back(ancestor);
+                }
+            }
+        }
+
+        let mut paths: Vec<Vec<NodeIndex>> = reached_roots
+            .into_iter()
+            .map(|root| {
+                let mut path = vec![root];
+                let mut current = root;
+
+                while let Some(&next) = predecessor.get(&current) {
+                    path.push(next);
+                    current = next;
+                }
+
+                path
+            })
+            .collect();
+
+        paths.sort_by(|a, b| {
+            a.len().cmp(&b.len()).then_with(|| {
+                graph[a[0]]
+                    .name
+                    .to_string()
+                    .cmp(&graph[b[0]].name.to_string())
+            })
+        });
+
+        paths
+    }
+
+    /// Render the report as a SARIF 2.1.0 log (for GitHub/GitLab code scanning)
+    fn sarif_log(&self, report: &rustsec::Report) -> serde_json::Value {
+        let artifact_uri = self
+            .lockfile_path
+            .as_deref()
+            .unwrap_or_else(|| Path::new("Cargo.lock"))
+            .display()
+            .to_string();
+
+        let mut rules = vec![];
+        let mut rule_ids = Set::new();
+        let mut results = vec![];
+
+        for vulnerability in &report.vulnerabilities.list {
+            let advisory = &vulnerability.advisory;
+            let rule_id = advisory.id.to_string();
+
+            if rule_ids.insert(rule_id.clone()) {
+                rules.push(self.sarif_rule(&rule_id, advisory));
+            }
+
+            let solution = vulnerability
+                .versions
+                .patched
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" OR ");
+
+            results.push(serde_json::json!({
+                "ruleId": rule_id,
+                "level": "error",
+                "message": {
+                    "text": format!(
+                        "{}\nAffected crate: {} {}\nSolution: upgrade to {}",
+                        advisory.title, vulnerability.package.name, vulnerability.package.version, solution
+                    ),
+                },
+                "locations": [self.sarif_location(&artifact_uri)],
+            }));
+        }
+
+        for warning in &report.warnings {
+            let (rule, result) = self.sarif_warning_entry(warning, &mut rule_ids, &artifact_uri);
+
+            if let Some(rule) = rule {
+                rules.push(rule);
+            }
+
+            results.push(result);
+        }
+
+        serde_json::json!({
+            "version": "2.1.0",
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "cargo-audit",
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        })
+    }
+
+    /// Build the SARIF `rules[]` entry (when this warning's rule id hasn't been seen yet)
+    /// and `results[]` entry for a single warning, deduplicating rules by ID via `rule_ids`.
+    ///
+    /// Warnings with an advisory (e.g. unmaintained/unsound/informational notices) key their
+    /// rule off the advisory ID, same as vulnerabilities. Yanked (and other non-advisory)
+    /// warnings carry no `Metadata` to build a rule or message from, so they fall back to a
+    /// synthetic `{kind}/{package}` rule id built from the warning's kind and package instead.
+    fn sarif_warning_entry(
+        &self,
+        warning: &Warning,
+        rule_ids: &mut Set<String>,
+        artifact_uri: &str,
+    ) -> (Option<serde_json::Value>, serde_json::Value) {
+        let kind_label = warning_kind_label(&warning.kind);
+
+        match &warning.advisory {
+            Some(advisory) => {
+                let rule_id = advisory.id.to_string();
+                let rule = rule_ids
+                    .insert(rule_id.clone())
+                    .then(|| self.sarif_rule(&rule_id, advisory));
+
+                let result = serde_json::json!({
+                    "ruleId": rule_id,
+                    "level": "warning",
+                    "message": {
+                        "text": format!(
+                            "{}\nAffected crate: {}",
+                            advisory.title, warning.package.name
+                        ),
+                    },
+                    "locations": [self.sarif_location(artifact_uri)],
+                });
+
+                (rule, result)
+            }
+            None => {
+                let rule_id = format!("{kind_label}/{}", warning.package.name);
+                let rule = rule_ids.insert(rule_id.clone()).then(|| {
+                    serde_json::json!({
+                        "id": rule_id,
+                        "shortDescription": {
+                            "text": format!("{kind_label} crate: {}", warning.package.name),
+                        },
+                    })
+                });
+
+                let result = serde_json::json!({
+                    "ruleId": rule_id,
+                    "level": "warning",
+                    "message": {
+                        "text": format!(
+                            "Crate {} {} is {kind_label}",
+                            warning.package.name, warning.package.version
+                        ),
+                    },
+                    "locations": [self.sarif_location(artifact_uri)],
+                });
+
+                (rule, result)
+            }
+        }
+    }
+
+    /// Build a SARIF `rules[]` entry for a single advisory
+    fn sarif_rule(
+        &self,
+        rule_id: &str,
+        advisory: &rustsec::advisory::Metadata,
+    ) -> serde_json::Value {
+        let help_uri = advisory
+            .id
+            .url()
+            .or_else(|| advisory.url.as_ref().map(ToString::to_string));
+
+        serde_json::json!({
+            "id": rule_id,
+            "shortDescription": { "text": advisory.title },
+            "helpUri": help_uri,
+        })
+    }
+
+    /// Build a SARIF `locations[0]` entry pointing at the scanned `Cargo.lock`
+    fn sarif_location(&self, artifact_uri: &str) -> serde_json::Value {
+        serde_json::json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": artifact_uri },
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `root` depends on `dep-a`, which depends on `vulnerable`
+    const LOCKFILE: &str = r#"
+# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "root"
+version = "0.1.0"
+dependencies = [
+ "dep-a",
+]
+
+[[package]]
+name = "dep-a"
+version = "0.1.0"
+dependencies = [
+ "vulnerable",
+]
+
+[[package]]
+name = "vulnerable"
+version = "0.1.0"
+"#;
+
+    fn package<'l>(lockfile: &'l Lockfile, name: &str) -> &'l Package {
+        lockfile
+            .packages
+            .iter()
+            .find(|package| package.name.to_string() == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn shortest_paths_to_roots_finds_path_through_intermediate_deps() {
+        let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+        let tree = lockfile.dependency_tree().unwrap();
+        let presenter = Presenter::new(&OutputConfig::default());
+
+        let paths = presenter.shortest_paths_to_roots(package(&lockfile, "vulnerable"), &tree);
+        assert_eq!(paths.len(), 1);
+
+        let names: Vec<_> = paths[0]
+            .iter()
+            .map(|node| tree.graph()[*node].name.to_string())
+            .collect();
+        assert_eq!(names, vec!["root", "dep-a", "vulnerable"]);
+    }
+
+    #[test]
+    fn shortest_paths_to_roots_is_empty_for_a_root_package() {
+        let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+        let tree = lockfile.dependency_tree().unwrap();
+        let presenter = Presenter::new(&OutputConfig::default());
+
+        let paths = presenter.shortest_paths_to_roots(package(&lockfile, "root"), &tree);
+        assert!(paths.is_empty());
+    }
+
+    /// `root-a` and `root-b` both depend directly on `vulnerable`
+    const MULTI_ROOT_LOCKFILE: &str = r#"
+# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "root-a"
+version = "0.1.0"
+dependencies = [
+ "vulnerable",
+]
+
+[[package]]
+name = "root-b"
+version = "0.1.0"
+dependencies = [
+ "vulnerable",
+]
+
+[[package]]
+name = "vulnerable"
+version = "0.1.0"
+"#;
+
+    #[test]
+    fn shortest_paths_to_roots_orders_equal_length_paths_lexicographically() {
+        let lockfile: Lockfile = MULTI_ROOT_LOCKFILE.parse().unwrap();
+        let tree = lockfile.dependency_tree().unwrap();
+        let presenter = Presenter::new(&OutputConfig::default());
+
+        let paths = presenter.shortest_paths_to_roots(package(&lockfile, "vulnerable"), &tree);
+        assert_eq!(paths.len(), 2);
+
+        let roots: Vec<_> = paths
+            .iter()
+            .map(|path| tree.graph()[path[0]].name.to_string())
+            .collect();
+        assert_eq!(roots, vec!["root-a", "root-b"]);
+    }
+
+    #[test]
+    fn intersect_patched_versions_takes_the_highest_shared_floor() {
+        let reqs = vec![">=1.2.3".parse().unwrap(), ">=1.5.0".parse().unwrap()];
+
+        assert_eq!(
+            intersect_patched_versions(&reqs),
+            Some(semver::Version::new(1, 5, 0)),
+        );
+    }
+
+    #[test]
+    fn intersect_patched_versions_rejects_a_floor_outside_another_requirements_range() {
+        // The second advisory's range is bounded above at 2.0.0, but the first
+        // advisory's floor (2.5.0) is higher -- no version can satisfy both.
+        let reqs = vec![
+            ">=2.5.0".parse().unwrap(),
+            ">=1.2.3, <2.0.0".parse().unwrap(),
+        ];
+
+        assert_eq!(intersect_patched_versions(&reqs), None);
+    }
+
+    #[test]
+    fn sarif_warning_entry_falls_back_to_kind_and_package_for_yanked_warnings() {
+        let lockfile: Lockfile = LOCKFILE.parse().unwrap();
+        let presenter = Presenter::new(&OutputConfig::default());
+        let warning = Warning {
+            kind: Kind::Yanked,
+            package: package(&lockfile, "vulnerable").clone(),
+            advisory: None,
+        };
+
+        let mut rule_ids = Set::new();
+        let (rule, result) = presenter.sarif_warning_entry(&warning, &mut rule_ids, "Cargo.lock");
+
+        assert_eq!(result["ruleId"], "yanked/vulnerable");
+        assert_eq!(result["level"], "warning");
+        assert!(rule.is_some());
+
+        // A second warning with the same kind/package shares a rule id, so it must not
+        // register (and emit) a duplicate `rules[]` entry.
+        let (duplicate_rule, duplicate_result) =
+            presenter.sarif_warning_entry(&warning, &mut rule_ids, "Cargo.lock");
+        assert_eq!(duplicate_result["ruleId"], "yanked/vulnerable");
+        assert!(duplicate_rule.is_none());
+    }
+
+    #[test]
+    fn sarif_location_points_at_the_scanned_lockfile() {
+        let presenter = Presenter::new(&OutputConfig::default());
+        let location = presenter.sarif_location("Cargo.lock");
+
+        assert_eq!(
+            location["physicalLocation"]["artifactLocation"]["uri"],
+            "Cargo.lock",
+        );
+    }
 }